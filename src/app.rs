@@ -1,10 +1,11 @@
-use regex::Regex;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::console::debug;
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 use gloo_net::http::Request;
+use gloo_timers::callback::Interval;
+use js_sys::Date;
 use std::fmt;
 
 #[wasm_bindgen]
@@ -13,39 +14,251 @@ extern "C" {
     async fn invoke(cmd: &str, args: JsValue) -> JsValue;
 }
 
+#[derive(Serialize)]
+struct NotifyArgs {
+    title: String,
+    body: String,
+}
+
+/// Per-hazard trigger levels for desktop notifications. Categorical is
+/// compared against the same `dn` code `GetRisk` renders; the others are
+/// plain percentages.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlertThresholds {
+    pub categorical: i32,
+    pub tornado: i32,
+    pub wind: i32,
+    pub hail: i32,
+}
+
+impl AlertThresholds {
+    pub fn new() -> Self {
+        Self {
+            categorical: 5, // ENH
+            tornado: 10,
+            wind: 10,
+            hail: 10,
+        }
+    }
+
+    fn for_hazard(&self, hazard: &str) -> i32 {
+        match hazard {
+            "categorical" => self.categorical,
+            "tornado" => self.tornado,
+            "wind" => self.wind,
+            "hail" => self.hail,
+            _ => i32::MAX,
+        }
+    }
+}
+
+/// Whether a hazard reading should change its entry in `NotifiedState`:
+/// `Some(true)` to insert and fire a notification (newly crossed),
+/// `Some(false)` to remove (dropped back below threshold since the last
+/// notified reading), `None` to leave the notified state untouched.
+fn crossing_transition(crossed: bool, already_notified: bool) -> Option<bool> {
+    match (crossed, already_notified) {
+        (true, false) => Some(true),
+        (false, true) => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod alert_logic_tests {
+    use super::*;
+
+    #[test]
+    fn for_hazard_maps_each_known_hazard() {
+        let t = AlertThresholds {
+            categorical: 1,
+            tornado: 2,
+            wind: 3,
+            hail: 4,
+        };
+        assert_eq!(t.for_hazard("categorical"), 1);
+        assert_eq!(t.for_hazard("tornado"), 2);
+        assert_eq!(t.for_hazard("wind"), 3);
+        assert_eq!(t.for_hazard("hail"), 4);
+        assert_eq!(t.for_hazard("unknown"), i32::MAX);
+    }
+
+    #[test]
+    fn newly_crossed_inserts_and_notifies() {
+        assert_eq!(crossing_transition(true, false), Some(true));
+    }
+
+    #[test]
+    fn already_notified_and_still_crossed_is_a_no_op() {
+        assert_eq!(crossing_transition(true, true), None);
+    }
+
+    #[test]
+    fn dropping_back_below_threshold_removes() {
+        assert_eq!(crossing_transition(false, true), Some(false));
+    }
+
+    #[test]
+    fn never_crossed_is_a_no_op() {
+        assert_eq!(crossing_transition(false, false), None);
+    }
+}
+
+async fn notify_threshold_crossed(hazard: &str, value: i32, threshold: i32) {
+    let args = NotifyArgs {
+        title: format!("{hazard} risk alert"),
+        body: format!("{hazard} reached {value} (threshold {threshold})"),
+    };
+    if let Ok(args) = serde_wasm_bindgen::to_value(&args) {
+        invoke("notify", args).await;
+    }
+}
+
 const ARCGIS_BASE_URL: &str = "https://mapservices.weather.noaa.gov/vector/rest/services/outlooks/SPC_wx_outlks/MapServer/";
+const NOMINATIM_BASE_URL: &str = "https://nominatim.openstreetmap.org/search";
 
-// These will be removed in the future in favor of configurability
-// Currently hardcoded to Huntsville, AL
-const LATITUDE: f64 = 34.7382;
-const LONGITUDE: f64 = -86.6018;
+// Default location until the user searches for somewhere else.
+const DEFAULT_LATITUDE: f64 = 34.7382;
+const DEFAULT_LONGITUDE: f64 = -86.6018;
+const DEFAULT_STATION: &str = "KHSV";
 
+// A handful of major CONUS stations used to resolve a coordinate pair to the
+// nearest NBM text station. Good enough for an approximate nearest-neighbor
+// lookup; not meant to be exhaustive.
+const STATIONS: &[(&str, f64, f64)] = &[
+    ("KHSV", 34.6372, -86.7751),
+    ("KATL", 33.6407, -84.4277),
+    ("KBNA", 36.1245, -86.6782),
+    ("KORD", 41.9742, -87.9073),
+    ("KDFW", 32.8998, -97.0403),
+    ("KDEN", 39.8561, -104.6737),
+    ("KLAX", 33.9416, -118.4085),
+    ("KSFO", 37.6213, -122.3790),
+    ("KSEA", 47.4502, -122.3088),
+    ("KJFK", 40.6413, -73.7781),
+    ("KMIA", 25.7959, -80.2871),
+    ("KMSP", 44.8848, -93.2223),
+    ("KPHX", 33.4352, -112.0101),
+    ("KSLC", 40.7899, -111.9791),
+    ("KOKC", 35.3931, -97.6007),
+];
+
+fn nearest_station(lat: f64, lon: f64) -> String {
+    STATIONS
+        .iter()
+        .min_by(|(_, a_lat, a_lon), (_, b_lat, b_lon)| {
+            let a_dist = (lat - a_lat).powi(2) + (lon - a_lon).powi(2);
+            let b_dist = (lat - b_lat).powi(2) + (lon - b_lon).powi(2);
+            a_dist.total_cmp(&b_dist)
+        })
+        .map(|(station, _, _)| station.to_string())
+        .unwrap_or_else(|| DEFAULT_STATION.to_string())
+}
+
+/// `dn` is the ArcGIS MapServer sublayer index, used as the `/{dn}/query`
+/// path segment against `ARCGIS_BASE_URL`. `Day1*` values (1, 3, 5, 7) were
+/// the layers this dashboard already queried pre-chunk0-2 and are known
+/// good. The `Day2*`/`Day4-8Outlook` values are extrapolated from that
+/// same odd-numbered spacing and have NOT been independently confirmed
+/// against the live service's `/legend` or `/layers` endpoint — there was
+/// no network access available to do so when this was written. A wrong
+/// `dn` doesn't error loudly (see the `Err` handling in `GetRisk`), it
+/// just returns an empty feature set that reads as a legitimate "NONE"
+/// risk. Until someone confirms these against the live service,
+/// `is_experimental` keeps them out of the default day selector and out
+/// of desktop-alert eligibility — see `AppState::show_experimental_days`.
 #[derive(Debug, PartialEq, Copy, Clone)]
 enum MapServer {
     Day1Outlook = 1,
     Day1Tornado = 3,
     Day1Hail = 5,
     Day1Wind = 7,
+    Day2Outlook = 9,
+    Day2Tornado = 11,
+    Day2Hail = 13,
+    Day2Wind = 15,
+    Day4Outlook = 17,
+    Day5Outlook = 19,
+    Day6Outlook = 21,
+    Day7Outlook = 23,
+    Day8Outlook = 25,
 }
 
 impl MapServer {
     fn get_common_name(&self) -> String {
         match self {
-            Self::Day1Outlook => "categorical".to_string(),
-            Self::Day1Tornado => "tornado".to_string(),
-            Self::Day1Hail => "hail".to_string(),
-            Self::Day1Wind => "wind".to_string(),
+            Self::Day1Outlook
+            | Self::Day2Outlook
+            | Self::Day4Outlook
+            | Self::Day5Outlook
+            | Self::Day6Outlook
+            | Self::Day7Outlook
+            | Self::Day8Outlook => "categorical".to_string(),
+            Self::Day1Tornado | Self::Day2Tornado => "tornado".to_string(),
+            Self::Day1Hail | Self::Day2Hail => "hail".to_string(),
+            Self::Day1Wind | Self::Day2Wind => "wind".to_string(),
         }
     }
 
     fn get_dn(&self) -> i32 {
-        match self {
-            Self::Day1Outlook => 1,
-            Self::Day1Tornado => 3,
-            Self::Day1Hail => 5,
-            Self::Day1Wind => 7,
+        *self as i32
+    }
+
+    /// The categorical outlook layer for a given outlook day (1, 2, or 4-8).
+    fn outlook(day: u8) -> MapServer {
+        match day {
+            1 => Self::Day1Outlook,
+            2 => Self::Day2Outlook,
+            4 => Self::Day4Outlook,
+            5 => Self::Day5Outlook,
+            6 => Self::Day6Outlook,
+            7 => Self::Day7Outlook,
+            8 => Self::Day8Outlook,
+            _ => Self::Day1Outlook,
+        }
+    }
+
+    fn tornado(day: u8) -> Option<MapServer> {
+        match day {
+            1 => Some(Self::Day1Tornado),
+            2 => Some(Self::Day2Tornado),
+            _ => None,
+        }
+    }
+
+    fn hail(day: u8) -> Option<MapServer> {
+        match day {
+            1 => Some(Self::Day1Hail),
+            2 => Some(Self::Day2Hail),
+            _ => None,
+        }
+    }
+
+    fn wind(day: u8) -> Option<MapServer> {
+        match day {
+            1 => Some(Self::Day1Wind),
+            2 => Some(Self::Day2Wind),
+            _ => None,
         }
     }
+
+    /// Only Day 1-2 outlooks carry probabilistic tornado/wind/hail sublayers;
+    /// Day 4-8 are categorical-only.
+    fn has_probabilistic_layers(day: u8) -> bool {
+        matches!(day, 1 | 2)
+    }
+
+    /// Whether this layer's `dn` is an unconfirmed guess (see the enum's
+    /// doc comment). Experimental layers are hidden from the day selector
+    /// by default and never trigger desktop alerts, since a wrong `dn`
+    /// silently reads as a legitimate "NONE"/low risk rather than erroring.
+    fn is_experimental(&self) -> bool {
+        !matches!(self, Self::Day1Outlook | Self::Day1Tornado | Self::Day1Hail | Self::Day1Wind)
+    }
+
+    fn outlook_image_url(day: u8) -> String {
+        format!("https://www.spc.noaa.gov/products/outlook/day{day}otlk.gif")
+    }
 }
 
 impl fmt::Display for MapServer {
@@ -75,9 +288,9 @@ struct Attributes {
     dn: i32,
 }
 
-async fn fetch_risk(map_server: MapServer) -> Result<i32, String> {
+async fn fetch_risk(map_server: MapServer, lat: f64, lon: f64) -> Result<i32, String> {
     let dn = map_server.get_dn();
-    let url = format!("{ARCGIS_BASE_URL}/{dn}/query?f=json&geometry={LONGITUDE},{LATITUDE}&geometryType=esriGeometryPoint\
+    let url = format!("{ARCGIS_BASE_URL}/{dn}/query?f=json&geometry={lon},{lat}&geometryType=esriGeometryPoint\
          &inSR=4326&spatialRel=esriSpatialRelIntersects&outFields=*");
 
     let response: ArcGisResponse = Request::get(&url)
@@ -95,32 +308,237 @@ async fn fetch_risk(map_server: MapServer) -> Result<i32, String> {
     }
 }
 
-fn parse_temps(lines: &[&str]) -> Option<(i32, i32)> {
-    let mut high = None;
-    let mut low = None;
+/// One column of the NBM text grid: a single forecast hour across all
+/// tracked elements.
+#[derive(Debug, Clone, PartialEq)]
+struct Forecast {
+    valid_time: String,
+    temp: Option<i32>,
+    /// 6-hr precipitation probability, from the bulletin's `P06` row (NBS
+    /// text products don't have a plain "PoP" row; `P12` is the coarser
+    /// 12-hr counterpart).
+    pop: Option<i32>,
+    wind: Option<i32>,
+    gust: Option<i32>,
+    sky: Option<i32>,
+}
+
+const FORECAST_ROW_LABELS: &[&str] = &["TMP", "P06", "WSP", "GST", "SKY"];
+
+fn parse_forecast(lines: &[&str]) -> Vec<Forecast> {
+    // A multi-day bulletin repeats the UTC/TMP/P06/... header+data block once
+    // per screen rather than emitting one wide row, so each label can appear
+    // more than once; append to the running row instead of overwriting it,
+    // mirroring how `valid_times` already accumulates across blocks.
+    let mut valid_times: Vec<String> = Vec::new();
+    let mut rows: std::collections::HashMap<&str, Vec<Option<i32>>> = std::collections::HashMap::new();
 
     for line in lines {
         let parts: Vec<&str> = line.trim().split_whitespace().collect();
-        web_sys::console::log_1(&JsValue::from_str(&format!("{:?}", parts).to_string()));
-        if parts.first() == Some(&"TXN") {
-            low = parts.get(1)?.parse::<i32>().ok();
-            high = parts.get(2)?.parse::<i32>().ok();
+        let Some(label) = parts.first() else { continue };
+
+        if *label == "UTC" {
+            valid_times.extend(parts[1..].iter().map(|s| s.to_string()));
+        } else if FORECAST_ROW_LABELS.contains(label) {
+            let values = parts[1..]
+                .iter()
+                .map(|v| if *v == "-" { None } else { v.parse::<i32>().ok() });
+            rows.entry(*label).or_default().extend(values);
         }
     }
 
-    match (high, low) {
-        (Some(h), Some(l)) => Some((h, l)),
-        _ => None,
+    let empty: Vec<Option<i32>> = Vec::new();
+    let temps = rows.get("TMP").unwrap_or(&empty);
+    let pops = rows.get("P06").unwrap_or(&empty);
+    let winds = rows.get("WSP").unwrap_or(&empty);
+    let gusts = rows.get("GST").unwrap_or(&empty);
+    let skies = rows.get("SKY").unwrap_or(&empty);
+
+    valid_times
+        .into_iter()
+        .enumerate()
+        .map(|(i, valid_time)| Forecast {
+            valid_time,
+            temp: temps.get(i).copied().flatten(),
+            pop: pops.get(i).copied().flatten(),
+            wind: winds.get(i).copied().flatten(),
+            gust: gusts.get(i).copied().flatten(),
+            sky: skies.get(i).copied().flatten(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod parse_forecast_tests {
+    use super::*;
+
+    #[test]
+    fn reads_p06_as_pop() {
+        let bulletin = "\
+KHSV    NBS GUIDANCE    7/30/2026  1200 UTC
+UTC  18 19 20 21
+TMP  88 87 85 82
+P06   -  -  20  -
+WSP  10  9  8  7
+GST  15 14 13 12
+SKY 100 90 80 70";
+        let lines: Vec<&str> = bulletin.lines().collect();
+
+        let forecast = parse_forecast(&lines);
+
+        assert_eq!(forecast.len(), 4);
+        assert_eq!(forecast[0].pop, None);
+        assert_eq!(forecast[2].pop, Some(20));
+        assert_eq!(forecast[2].temp, Some(85));
+    }
+
+    #[test]
+    fn concatenates_repeated_blocks_instead_of_overwriting() {
+        // Real NBS bulletins repeat the UTC/TMP/.../SKY block once per
+        // screen to cover the full multi-day window.
+        let bulletin = "\
+KHSV    NBS GUIDANCE    7/30/2026  1200 UTC
+UTC  18 19 20 21
+TMP  88 87 85 82
+P06   -  -  20  -
+WSP  10  9  8  7
+GST  15 14 13 12
+SKY 100 90 80 70
+UTC  22 23 24 25
+TMP  80 78 76 75
+P06  10  -  -  -
+WSP   6  5  4  4
+GST  11 10  9  9
+SKY  60 50 40 30";
+        let lines: Vec<&str> = bulletin.lines().collect();
+
+        let forecast = parse_forecast(&lines);
+
+        assert_eq!(forecast.len(), 8);
+        assert_eq!(forecast[2].temp, Some(85));
+        assert_eq!(forecast[4].valid_time, "22");
+        assert_eq!(forecast[4].temp, Some(80));
+        assert_eq!(forecast[4].pop, Some(10));
+        assert_eq!(forecast[7].temp, Some(75));
     }
 }
 
-async fn fetch_daycast() -> Result<(i32, i32), String> {
-    let url = "https://blend.mdl.nws.noaa.gov/nbm-text-new?ele=NBS&sta=KHSV&cyc=Latest";
+async fn fetch_daycast(station: &str) -> Result<Vec<Forecast>, String> {
+    let url = format!("https://blend.mdl.nws.noaa.gov/nbm-text-new?ele=NBS&sta={station}&cyc=Latest");
     let response = Request::get(&url).send().await.map_err(|e| e.to_string())?.text().await.map_err(|e| e.to_string())?;
     let lines: Vec<_> = response.lines().collect();
-    let (hi, lo) = parse_temps(&lines).ok_or_else(|| "Temps not found")?;
+    let forecast = parse_forecast(&lines);
+
+    if forecast.is_empty() {
+        return Err("Forecast not found".to_string());
+    }
+
+    Ok(forecast)
+}
+
+#[derive(Debug, Deserialize)]
+struct GeocodeResult {
+    lat: String,
+    lon: String,
+}
+
+async fn geocode(query: &str) -> Result<(f64, f64), String> {
+    let encoded = js_sys::encode_uri_component(query.trim())
+        .as_string()
+        .unwrap_or_default();
+    let url = format!("{NOMINATIM_BASE_URL}?format=json&q={encoded}");
+    let results: Vec<GeocodeResult> = Request::get(&url)
+        .header("User-Agent", "meso-weather-dashboard")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let first = results.into_iter().next().ok_or("No results found")?;
+    let lat = first.lat.parse::<f64>().map_err(|e| e.to_string())?;
+    let lon = first.lon.parse::<f64>().map_err(|e| e.to_string())?;
+
+    Ok((lat, lon))
+}
+
+fn now_label() -> String {
+    Date::new_0()
+        .to_locale_time_string("en-US")
+        .as_string()
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Deserialize)]
+struct AqiResponse {
+    current: AqiCurrent,
+}
+
+#[derive(Debug, Deserialize)]
+struct AqiCurrent {
+    us_aqi: f64,
+}
+
+fn aqi_category(aqi: i32) -> &'static str {
+    match aqi {
+        0..=50 => "good",
+        51..=100 => "moderate",
+        101..=150 => "sensitive",
+        151..=200 => "unhealthy",
+        201..=300 => "very-unhealthy",
+        _ => "hazardous",
+    }
+}
+
+async fn fetch_aqi(lat: f64, lon: f64) -> Result<(i32, String), String> {
+    let url = format!(
+        "https://air-quality-api.open-meteo.com/v1/air-quality?latitude={lat}&longitude={lon}&current=us_aqi"
+    );
+    let response: AqiResponse = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
 
-    Ok((hi, lo))
+    let aqi = response.current.us_aqi.round() as i32;
+    Ok((aqi, aqi_category(aqi).to_string()))
+}
+
+#[derive(Debug, Deserialize)]
+struct UvResponse {
+    current: UvCurrent,
+}
+
+#[derive(Debug, Deserialize)]
+struct UvCurrent {
+    uv_index: f64,
+}
+
+fn uv_category(uv: i32) -> &'static str {
+    match uv {
+        0..=2 => "low",
+        3..=5 => "moderate",
+        6..=7 => "high",
+        8..=10 => "very-high",
+        _ => "extreme",
+    }
+}
+
+async fn fetch_uv(lat: f64, lon: f64) -> Result<(i32, String), String> {
+    let url = format!("https://api.open-meteo.com/v1/forecast?latitude={lat}&longitude={lon}&current=uv_index");
+    let response: UvResponse = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let uv = response.current.uv_index.round() as i32;
+    Ok((uv, uv_category(uv).to_string()))
 }
 
 #[component]
@@ -147,15 +565,50 @@ fn Climate() -> Html {
 
 #[component]
 fn GetRisk(MapServerProps { map_server }: &MapServerProps) -> Html {
+    let state = use_context::<UseStateHandle<AppState>>().expect("AppState context not found");
+    let refresh = use_context::<UseStateHandle<RefreshState>>().expect("RefreshState context not found");
+    let notified = use_context::<UseReducerHandle<NotifiedState>>().expect("NotifiedState context not found");
     let risk = use_state(|| None::<i32>);
-    let ms = map_server.clone();
+    let last_updated = use_state(|| None::<String>);
+    let ms = *map_server;
+    let lat = state.lat;
+    let lon = state.lon;
+    let day = state.selected_day;
+    let station = state.station.clone();
+    let thresholds = state.alert_thresholds;
+    let generation = refresh.generation;
     {
         let risk = risk.clone();
-        use_effect_with((), move |_| {
+        let last_updated = last_updated.clone();
+        let notified = notified.clone();
+        use_effect_with((ms, lat, lon, generation), move |_| {
             wasm_bindgen_futures::spawn_local(async move {
-                let result = fetch_risk(ms).await;
+                let result = fetch_risk(ms, lat, lon).await;
                 if let Ok(r) = result {
                     risk.set(Some(r));
+                    last_updated.set(Some(now_label()));
+
+                    // Experimental layers (unconfirmed `dn`) never raise alerts:
+                    // a wrong dn silently reads as a legitimate low risk, so a
+                    // threshold "crossing" here isn't trustworthy.
+                    if !ms.is_experimental() {
+                        let risk_name = ms.get_common_name();
+                        let threshold = thresholds.for_hazard(&risk_name);
+                        let key = format!("{station}-{day}-{risk_name}");
+                        let crossed = r >= threshold;
+                        let already_notified = notified.0.contains(&key);
+
+                        match crossing_transition(crossed, already_notified) {
+                            Some(true) => {
+                                notified.dispatch(NotifiedAction::Insert(key));
+                                notify_threshold_crossed(&risk_name, r, threshold).await;
+                            }
+                            Some(false) => notified.dispatch(NotifiedAction::Remove(key)),
+                            None => {}
+                        }
+                    }
+                } else {
+                    web_sys::console::log_1(&JsValue::from_str(&format!("{ms} risk fetch failed: {:?}", result)));
                 }
             });
             || ()
@@ -170,35 +623,258 @@ fn GetRisk(MapServerProps { map_server }: &MapServerProps) -> Html {
                         let risk_name = map_server.get_common_name();
                         let color = format!("{risk_name}-{}", r.to_string().to_lowercase());
 
+                        let unverified = if ms.is_experimental() {
+                            html! { <span class="unverified-badge" title="This day's layer ID has not been confirmed against the live service.">{" (unconfirmed)"}</span> }
+                        } else {
+                            html! {}
+                        };
+
                         if risk_name == "categorical" {
                             if *r == 0 {
-                                html! { <p1 class={color}>{"NONE"}</p1> }
+                                html! { <><p1 class={color}>{"NONE"}</p1>{unverified}</> }
                             } else {
                                 let caps = r.to_string().to_uppercase();
-                                html! { <p1 class={color}>{format!("{caps}")}</p1> }
+                                html! { <><p1 class={color}>{format!("{caps}")}</p1>{unverified}</> }
                             }
                         } else {
-                            html! { <p1 class={color}>{format!("{r}%")}</p1> }
+                            html! { <><p1 class={color}>{format!("{r}%")}</p1>{unverified}</> }
                         }
                     },
                     None => html! { "None" },
                 }
             }
+            {
+                if let Some(ts) = &*last_updated {
+                    html! { <p class="last-updated">{format!("Updated {ts}")}</p> }
+                } else {
+                    html! {}
+                }
+            }
+        </div>
+    }
+}
+
+/// Picks the dominant hazard out of a tornado/wind/hail reading, returning
+/// its label and value. `Iterator::max_by_key` keeps the last of several
+/// equal elements, so the candidates are walked in reverse and ties break
+/// toward tornado, then wind, then hail.
+fn pick_max_hazard(tornado: i32, wind: i32, hail: i32) -> (&'static str, i32) {
+    [("tornado", tornado), ("wind", wind), ("hail", hail)]
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, v)| *v)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod pick_max_hazard_tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_largest_value() {
+        assert_eq!(pick_max_hazard(5, 30, 10), ("wind", 30));
+    }
+
+    #[test]
+    fn breaks_ties_toward_tornado_first() {
+        assert_eq!(pick_max_hazard(20, 20, 20), ("tornado", 20));
+    }
+
+    #[test]
+    fn breaks_wind_hail_ties_toward_wind() {
+        assert_eq!(pick_max_hazard(0, 15, 15), ("wind", 15));
+    }
+}
+
+/// Combined worst-case severe risk: the maximum probabilistic value across
+/// the tornado, wind, and hail layers, tagged with whichever hazard drove it.
+#[component]
+fn MaxHazard() -> Html {
+    let state = use_context::<UseStateHandle<AppState>>().expect("AppState context not found");
+    let refresh = use_context::<UseStateHandle<RefreshState>>().expect("RefreshState context not found");
+    let torn = use_state(|| None::<i32>);
+    let wind = use_state(|| None::<i32>);
+    let hail = use_state(|| None::<i32>);
+    let last_updated = use_state(|| None::<String>);
+    let lat = state.lat;
+    let lon = state.lon;
+    let day = state.selected_day;
+    let generation = refresh.generation;
+    let experimental = MapServer::tornado(day).map(|ms| ms.is_experimental()).unwrap_or(false);
+
+    {
+        let torn = torn.clone();
+        let wind = wind.clone();
+        let hail = hail.clone();
+        let last_updated = last_updated.clone();
+        use_effect_with((day, lat, lon, generation), move |_| {
+            if let Some(ms) = MapServer::tornado(day) {
+                let torn = torn.clone();
+                let last_updated = last_updated.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Ok(r) = fetch_risk(ms, lat, lon).await {
+                        torn.set(Some(r));
+                        last_updated.set(Some(now_label()));
+                    }
+                });
+            }
+            if let Some(ms) = MapServer::wind(day) {
+                let wind = wind.clone();
+                let last_updated = last_updated.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Ok(r) = fetch_risk(ms, lat, lon).await {
+                        wind.set(Some(r));
+                        last_updated.set(Some(now_label()));
+                    }
+                });
+            }
+            if let Some(ms) = MapServer::hail(day) {
+                let hail = hail.clone();
+                let last_updated = last_updated.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Ok(r) = fetch_risk(ms, lat, lon).await {
+                        hail.set(Some(r));
+                        last_updated.set(Some(now_label()));
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    html! {
+        <div>
+            {
+                match (*torn, *wind, *hail) {
+                    (Some(t), Some(w), Some(h)) => {
+                        let (label, max) = pick_max_hazard(t, w, h);
+                        let color = format!("{label}-{}", max.to_string().to_lowercase());
+                        let unverified = if experimental {
+                            html! { <span class="unverified-badge" title="This day's layer ID has not been confirmed against the live service.">{" (unconfirmed)"}</span> }
+                        } else {
+                            html! {}
+                        };
+                        html! {
+                            <>
+                                <p1 class={color}>{format!("{max}% (driven by {label})")}</p1>
+                                {unverified}
+                                <div class="status-row">
+                                    <span class="label">{format!("Tornado {t}%")}</span>
+                                    <span class="label">{format!("Wind {w}%")}</span>
+                                    <span class="label">{format!("Hail {h}%")}</span>
+                                </div>
+                            </>
+                        }
+                    }
+                    _ => html! { "None" },
+                }
+            }
+            {
+                if let Some(ts) = &*last_updated {
+                    html! { <p class="last-updated">{format!("Updated {ts}")}</p> }
+                } else {
+                    html! {}
+                }
+            }
+        </div>
+    }
+}
+
+/// Which "go outside" metric a `MetricPanel` renders; both fetch a single
+/// value + category keyed on the current coordinates, same as `fetch_risk`.
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Metric {
+    Aqi,
+    Uv,
+}
+
+impl Metric {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Aqi => "aqi",
+            Self::Uv => "uv",
+        }
+    }
+
+    async fn fetch(&self, lat: f64, lon: f64) -> Result<(i32, String), String> {
+        match self {
+            Self::Aqi => fetch_aqi(lat, lon).await,
+            Self::Uv => fetch_uv(lat, lon).await,
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct MetricProps {
+    metric: Metric,
+}
+
+#[component]
+fn MetricPanel(MetricProps { metric }: &MetricProps) -> Html {
+    let state = use_context::<UseStateHandle<AppState>>().expect("AppState context not found");
+    let refresh = use_context::<UseStateHandle<RefreshState>>().expect("RefreshState context not found");
+    let reading = use_state(|| None::<(i32, String)>);
+    let last_updated = use_state(|| None::<String>);
+    let metric = *metric;
+    let lat = state.lat;
+    let lon = state.lon;
+    let generation = refresh.generation;
+
+    {
+        let reading = reading.clone();
+        let last_updated = last_updated.clone();
+        use_effect_with((metric, lat, lon, generation), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(r) = metric.fetch(lat, lon).await {
+                    reading.set(Some(r));
+                    last_updated.set(Some(now_label()));
+                }
+            });
+            || ()
+        });
+    }
+
+    html! {
+        <div>
+            {
+                match &*reading {
+                    Some((value, category)) => {
+                        let color = format!("{}-{category}", metric.label());
+                        html! { <p1 class={color}>{format!("{value} ({category})")}</p1> }
+                    }
+                    None => html! { "None" },
+                }
+            }
+            {
+                if let Some(ts) = &*last_updated {
+                    html! { <p class="last-updated">{format!("Updated {ts}")}</p> }
+                } else {
+                    html! {}
+                }
+            }
         </div>
     }
 }
 
 #[component]
 fn GetTemp() -> Html {
-    let temps = use_state(|| (None::<i32>, None::<i32>));
+    let state = use_context::<UseStateHandle<AppState>>().expect("AppState context not found");
+    let refresh = use_context::<UseStateHandle<RefreshState>>().expect("RefreshState context not found");
+    let forecast = use_state(Vec::<Forecast>::new);
+    let last_updated = use_state(|| None::<String>);
+    let station = state.station.clone();
+    let generation = refresh.generation;
 
     {
-        let temps = temps.clone();
-        use_effect_with((), move |_| {
+        let forecast = forecast.clone();
+        let last_updated = last_updated.clone();
+        use_effect_with((station, generation), move |(station, _)| {
+            let station = station.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                let result = fetch_daycast().await;
+                let result = fetch_daycast(&station).await;
                 if let Ok(r) = result {
-                    temps.set((Some(r.0), Some(r.1)));
+                    forecast.set(r);
+                    last_updated.set(Some(now_label()));
                 } else {
                     web_sys::console::log_1(&JsValue::from_str(&format!("{:?}", result).to_string()));
                 }
@@ -210,23 +886,32 @@ fn GetTemp() -> Html {
     html! {
         <div>
             {
-                match &*temps {
-                    (Some(h), Some(l)) => {
-                        html! {
-                            <>
-                                <p style="font-size: 16pt;">{format!("{h}°F")}</p>
-                                <p>{format!("{l}°F")}</p>
-                            </>
-                        }
-                    }
-                    _ => {
-                        html! {
-                            <>
-                                <p style="font-size: 16pt;">{"-"}</p>
-                                <p>{"-"}</p>
-                            </>
-                        }
+                match forecast.first() {
+                    Some(f) => {
+                        html! { <p style="font-size: 16pt;">{f.temp.map(|t| format!("{t}°F")).unwrap_or_else(|| "-".to_string())}</p> }
                     }
+                    None => html! { <p style="font-size: 16pt;">{"-"}</p> },
+                }
+            }
+            <div class="hourly-strip">
+                {
+                    for forecast.iter().enumerate().map(|(i, f)| html! {
+                        // `valid_time` is an hour-of-day label (00-23) that wraps every
+                        // calendar day, so it alone isn't unique across a multi-day
+                        // forecast; key on the column index too.
+                        <div class="hourly-item" key={format!("{i}-{}", f.valid_time)}>
+                            <span class="hour">{&f.valid_time}</span>
+                            <span class="temp">{f.temp.map(|t| format!("{t}°")).unwrap_or_else(|| "-".to_string())}</span>
+                            <span class="pop">{f.pop.map(|p| format!("{p}% PoP")).unwrap_or_else(|| "-".to_string())}</span>
+                        </div>
+                    })
+                }
+            </div>
+            {
+                if let Some(ts) = &*last_updated {
+                    html! { <p class="last-updated">{format!("Updated {ts}")}</p> }
+                } else {
+                    html! {}
                 }
             }
         </div>
@@ -236,6 +921,18 @@ fn GetTemp() -> Html {
 #[derive(Clone, PartialEq)]
 pub struct AppState {
     pub outlook_url: String,
+    pub selected_day: u8,
+    pub lat: f64,
+    pub lon: f64,
+    pub station: String,
+    pub alert_thresholds: AlertThresholds,
+    pub show_aqi: bool,
+    pub show_uv: bool,
+    /// Reveals Day 2 and Day 4-8 outlook days in `DaySelector`. Off by
+    /// default: those days' `MapServer::get_dn` values are unconfirmed guesses
+    /// (see `MapServer`'s doc comment) and a wrong `dn` silently renders as
+    /// a legitimate-looking "NONE" risk rather than erroring.
+    pub show_experimental_days: bool,
 }
 
 impl AppState {
@@ -243,42 +940,388 @@ impl AppState {
         let outlook_url = "https://www.spc.noaa.gov/products/outlook/day1otlk.gif";
         Self {
             outlook_url: outlook_url.to_string(),
+            selected_day: 1,
+            lat: DEFAULT_LATITUDE,
+            lon: DEFAULT_LONGITUDE,
+            station: DEFAULT_STATION.to_string(),
+            alert_thresholds: AlertThresholds::new(),
+            show_aqi: true,
+            show_uv: true,
+            show_experimental_days: false,
+        }
+    }
+}
+
+/// Which hazard keys have already fired a desktop notification, so a
+/// sustained risk doesn't re-fire every refresh. Backed by `use_reducer`
+/// rather than folded into `AppState` so that `GetRisk`'s tornado/wind/hail
+/// siblings, which poll concurrently, can each dispatch an insert/removal
+/// against the latest set instead of racing on a stale clone.
+#[derive(Clone, PartialEq, Default)]
+pub struct NotifiedState(std::collections::HashSet<String>);
+
+pub enum NotifiedAction {
+    Insert(String),
+    Remove(String),
+}
+
+impl Reducible for NotifiedState {
+    type Action = NotifiedAction;
+
+    fn reduce(self: std::rc::Rc<Self>, action: Self::Action) -> std::rc::Rc<Self> {
+        let mut keys = self.0.clone();
+        match action {
+            NotifiedAction::Insert(key) => {
+                keys.insert(key);
+            }
+            NotifiedAction::Remove(key) => {
+                keys.remove(&key);
+            }
         }
+        std::rc::Rc::new(NotifiedState(keys))
+    }
+}
+
+#[component]
+pub fn AlertControls() -> Html {
+    let state = use_context::<UseStateHandle<AppState>>().expect("AppState context not found");
+    let thresholds = state.alert_thresholds;
+
+    let on_change = |field: &'static str| {
+        let state = state.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let Ok(value) = input.value().parse::<i32>() else { return };
+            let mut thresholds = state.alert_thresholds;
+            match field {
+                "categorical" => thresholds.categorical = value,
+                "tornado" => thresholds.tornado = value,
+                "wind" => thresholds.wind = value,
+                "hail" => thresholds.hail = value,
+                _ => {}
+            }
+            state.set(AppState {
+                alert_thresholds: thresholds,
+                ..(*state).clone()
+            });
+        })
+    };
+
+    html! {
+        <div class="alert-controls">
+            <label>
+                {"Categorical \u{2265} "}
+                <input type="number" min="0" value={thresholds.categorical.to_string()} onchange={on_change("categorical")} />
+            </label>
+            <label>
+                {"Tornado \u{2265} "}
+                <input type="number" min="0" max="100" value={thresholds.tornado.to_string()} onchange={on_change("tornado")} />
+                {"%"}
+            </label>
+            <label>
+                {"Wind \u{2265} "}
+                <input type="number" min="0" max="100" value={thresholds.wind.to_string()} onchange={on_change("wind")} />
+                {"%"}
+            </label>
+            <label>
+                {"Hail \u{2265} "}
+                <input type="number" min="0" max="100" value={thresholds.hail.to_string()} onchange={on_change("hail")} />
+                {"%"}
+            </label>
+        </div>
+    }
+}
+
+#[component]
+pub fn MetricToggles() -> Html {
+    let state = use_context::<UseStateHandle<AppState>>().expect("AppState context not found");
+
+    let toggle_aqi = {
+        let state = state.clone();
+        Callback::from(move |_| {
+            state.set(AppState {
+                show_aqi: !state.show_aqi,
+                ..(*state).clone()
+            });
+        })
+    };
+
+    let toggle_uv = {
+        let state = state.clone();
+        Callback::from(move |_| {
+            state.set(AppState {
+                show_uv: !state.show_uv,
+                ..(*state).clone()
+            });
+        })
+    };
+
+    html! {
+        <div class="metric-toggles">
+            <label>
+                <input type="checkbox" checked={state.show_aqi} onclick={toggle_aqi} />
+                {"Air Quality"}
+            </label>
+            <label>
+                <input type="checkbox" checked={state.show_uv} onclick={toggle_uv} />
+                {"UV Index"}
+            </label>
+        </div>
+    }
+}
+
+#[component]
+pub fn LocationSearch() -> Html {
+    let state = use_context::<UseStateHandle<AppState>>().expect("AppState context not found");
+    let query = use_state(String::new);
+    let error = use_state(|| None::<String>);
+
+    let oninput = {
+        let query = query.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            query.set(input.value());
+        })
+    };
+
+    let onsubmit = {
+        let state = state.clone();
+        let query = query.clone();
+        let error = error.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let state = state.clone();
+            let error = error.clone();
+            let q = (*query).clone();
+            if q.trim().is_empty() {
+                return;
+            }
+
+            spawn_local(async move {
+                match geocode(&q).await {
+                    Ok((lat, lon)) => {
+                        let station = nearest_station(lat, lon);
+                        state.set(AppState {
+                            lat,
+                            lon,
+                            station,
+                            ..(*state).clone()
+                        });
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    html! {
+        <form class="location-search" onsubmit={onsubmit}>
+            <input
+                type="text"
+                placeholder="Search a city or place..."
+                value={(*query).clone()}
+                oninput={oninput}
+            />
+            <button type="submit">{"Go"}</button>
+            {
+                if let Some(err) = &*error {
+                    html! { <p class="error">{err}</p> }
+                } else {
+                    html! {}
+                }
+            }
+        </form>
+    }
+}
+
+const DAYS: &[u8] = &[1, 2, 4, 5, 6, 7, 8];
+
+#[component]
+pub fn DaySelector() -> Html {
+    let state = use_context::<UseStateHandle<AppState>>().expect("AppState context not found");
+    let show_experimental = state.show_experimental_days;
+    let select_day = |day: u8| {
+        let state = state.clone();
+        Callback::from(move |_| {
+            state.set(AppState {
+                selected_day: day,
+                outlook_url: MapServer::outlook_image_url(day),
+                ..(*state).clone()
+            });
+        })
+    };
+    let toggle_experimental = {
+        let state = state.clone();
+        Callback::from(move |_| {
+            state.set(AppState {
+                show_experimental_days: !state.show_experimental_days,
+                ..(*state).clone()
+            });
+        })
+    };
+
+    html! {
+        <div class="day-selector">
+            {
+                for DAYS.iter().filter(|&&day| day == 1 || show_experimental).map(|&day| {
+                    let class = if state.selected_day == day { "day-button active" } else { "day-button" };
+                    html! {
+                        <button key={day} class={class} onclick={select_day(day)}>
+                            {format!("Day {day}")}
+                        </button>
+                    }
+                })
+            }
+            <label class="experimental-toggle">
+                <input type="checkbox" checked={show_experimental} onclick={toggle_experimental} />
+                {"Show Day 2 / 4-8 (unconfirmed layer IDs)"}
+            </label>
+        </div>
     }
 }
 
 #[component]
 pub fn OutlookButtons() -> Html {
     let state = use_context::<UseStateHandle<AppState>>().expect("AppsState not found");
-    let change_outlook = |src: &'static str| {
+    let day = state.selected_day;
+    let change_outlook = |src: String| {
         let state = state.clone();
         Callback::from(move |_| {
             state.set(AppState {
-                outlook_url: src.to_string(),
+                outlook_url: src.clone(),
+                ..(*state).clone()
             });
         })
     };
 
     html! {
         <>
-            <button style="margin-right: 16px; width: 100px;" onmouseenter={change_outlook("https://www.spc.noaa.gov/products/outlook/day1otlk.gif")}>{"Categorical"}</button>
-            <button style="margin-right: 16px; width: 100px;" onmouseenter={change_outlook("https://www.spc.noaa.gov/products/outlook/day1probotlk_torn.gif")}>{"Tornado"}</button>
-            <button style="margin-right: 16px; width: 100px;" onmouseenter={change_outlook("https://www.spc.noaa.gov/products/outlook/day1probotlk_wind.gif")}>{"Wind"}</button>
-            <button style="width: 100px;" onmouseenter={change_outlook("https://www.spc.noaa.gov/products/outlook/day1probotlk_hail.gif")}>{"Hail"}</button>
+            <button style="margin-right: 16px; width: 100px;" onmouseenter={change_outlook(MapServer::outlook_image_url(day))}>{"Categorical"}</button>
+            {
+                if MapServer::has_probabilistic_layers(day) {
+                    html! {
+                        <>
+                            <button style="margin-right: 16px; width: 100px;" onmouseenter={change_outlook(format!("https://www.spc.noaa.gov/products/outlook/day{day}probotlk_torn.gif"))}>{"Tornado"}</button>
+                            <button style="margin-right: 16px; width: 100px;" onmouseenter={change_outlook(format!("https://www.spc.noaa.gov/products/outlook/day{day}probotlk_wind.gif"))}>{"Wind"}</button>
+                            <button style="width: 100px;" onmouseenter={change_outlook(format!("https://www.spc.noaa.gov/products/outlook/day{day}probotlk_hail.gif"))}>{"Hail"}</button>
+                        </>
+                    }
+                } else {
+                    html! {}
+                }
+            }
         </>
     }
 }
 
+#[derive(Clone, PartialEq)]
+pub struct RefreshState {
+    pub generation: u32,
+    pub interval_secs: u32,
+}
+
+impl RefreshState {
+    pub fn new() -> Self {
+        Self {
+            generation: 0,
+            interval_secs: 300,
+        }
+    }
+}
+
+#[component]
+pub fn RefreshControls() -> Html {
+    let refresh = use_context::<UseStateHandle<RefreshState>>().expect("RefreshState context not found");
+    // Shared by both triggers below so every bump produces a distinct
+    // generation. Two independent counters writing the same field would let
+    // a manual refresh and the next timer tick land on the same number,
+    // which `use_effect_with`'s equality-based dependency check would then
+    // silently treat as "nothing changed" and skip the re-fetch.
+    let counter = use_mut_ref(|| 0u32);
+
+    {
+        let refresh = refresh.clone();
+        let counter = counter.clone();
+        use_effect_with(refresh.interval_secs, move |secs| {
+            let secs = *secs;
+            let refresh = refresh.clone();
+            let counter = counter.clone();
+            let interval = Interval::new(secs * 1000, move || {
+                let next = {
+                    let mut c = counter.borrow_mut();
+                    *c = c.wrapping_add(1);
+                    *c
+                };
+                refresh.set(RefreshState {
+                    generation: next,
+                    interval_secs: secs,
+                });
+            });
+            move || drop(interval)
+        });
+    }
+
+    let refresh_now = {
+        let refresh = refresh.clone();
+        let counter = counter.clone();
+        Callback::from(move |_| {
+            let next = {
+                let mut c = counter.borrow_mut();
+                *c = c.wrapping_add(1);
+                *c
+            };
+            refresh.set(RefreshState {
+                generation: next,
+                interval_secs: refresh.interval_secs,
+            });
+        })
+    };
+
+    let onchange = {
+        let refresh = refresh.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(secs) = input.value().parse::<u32>() {
+                refresh.set(RefreshState {
+                    generation: refresh.generation,
+                    interval_secs: secs,
+                });
+            }
+        })
+    };
+
+    html! {
+        <div class="refresh-controls">
+            <button onclick={refresh_now}>{"Refresh now"}</button>
+            <label>
+                {"Auto-refresh every "}
+                <input type="number" min="30" step="30" value={refresh.interval_secs.to_string()} onchange={onchange} />
+                {" seconds"}
+            </label>
+        </div>
+    }
+}
+
 #[component]
 pub fn App() -> Html {
     let app_state = use_state(AppState::new);
+    let refresh_state = use_state(RefreshState::new);
+    let notified_state = use_reducer(NotifiedState::default);
+    let selected_day = app_state.selected_day;
 
     html! {
         <ContextProvider<UseStateHandle<AppState>> context={app_state}>
+        <ContextProvider<UseStateHandle<RefreshState>> context={refresh_state}>
+        <ContextProvider<UseReducerHandle<NotifiedState>> context={notified_state}>
             <body>
                 <header>
                     <strong>{"Meso"}</strong>
                     {" | Weather Dashboard"}
+                    <LocationSearch />
+                    <RefreshControls />
+                    <AlertControls />
+                    <MetricToggles />
                 </header>
                 <main class="container" style="align-items: center;">
                     <div class="status-row">
@@ -287,30 +1330,71 @@ pub fn App() -> Html {
                             <GetTemp />
                         </section>
                         <section class="panel">
-                            <h2>{"Day 1 Categorical Outlook"}</h2>
-                            <GetRisk map_server={MapServer::Day1Outlook} />
-                            <h2>{"Risks by Type"}</h2>
-                            <div class="status-grid">
-                                <div class="status-row">
-                                    <div class="status-item" style="width: 150px;">
-                                        <span class="label">{"Tornado"}</span>
-                                        <span class="value"><GetRisk map_server={MapServer::Day1Tornado} /></span>
-                                    </div>
-                                    <div class="status-item" style="width: 150px;">
-                                        <span class="label">{"Wind"}</span>
-                                        <span class="value"><GetRisk map_server={MapServer::Day1Wind} /></span>
-                                    </div>
-                                    <div class="status-item" style="width: 150px;">
-                                        <span class="label">{"Hail"}</span>
-                                        <span class="value"><GetRisk map_server={MapServer::Day1Hail} /></span>
-                                    </div>
-                                </div>
-                            </div>
+                            <h2>{"Go Outside"}</h2>
+                            {
+                                if app_state.show_aqi {
+                                    html! {
+                                        <div class="status-item">
+                                            <span class="label">{"Air Quality"}</span>
+                                            <MetricPanel metric={Metric::Aqi} />
+                                        </div>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                            {
+                                if app_state.show_uv {
+                                    html! {
+                                        <div class="status-item">
+                                            <span class="label">{"UV Index"}</span>
+                                            <MetricPanel metric={Metric::Uv} />
+                                        </div>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                        </section>
+                        <section class="panel">
+                            <h2>{format!("Day {selected_day} Categorical Outlook")}</h2>
+                            <GetRisk map_server={MapServer::outlook(selected_day)} />
+                            {
+                                if MapServer::has_probabilistic_layers(selected_day) {
+                                    html! {
+                                        <>
+                                            <h2>{"Risks by Type"}</h2>
+                                            <div class="status-grid">
+                                                <div class="status-row">
+                                                    <div class="status-item" style="width: 150px;">
+                                                        <span class="label">{"Tornado"}</span>
+                                                        <span class="value"><GetRisk map_server={MapServer::tornado(selected_day).unwrap()} /></span>
+                                                    </div>
+                                                    <div class="status-item" style="width: 150px;">
+                                                        <span class="label">{"Wind"}</span>
+                                                        <span class="value"><GetRisk map_server={MapServer::wind(selected_day).unwrap()} /></span>
+                                                    </div>
+                                                    <div class="status-item" style="width: 150px;">
+                                                        <span class="label">{"Hail"}</span>
+                                                        <span class="value"><GetRisk map_server={MapServer::hail(selected_day).unwrap()} /></span>
+                                                    </div>
+                                                </div>
+                                            </div>
+                                            <h2>{"Max Hazard"}</h2>
+                                            <MaxHazard />
+                                        </>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
                         </section>
                     </div>
                     <section class="panel" style="width: 675px;">
                         <h2>{"SPC Outlook Map"}</h2>
                         <div class="status-item">
+                            <DaySelector />
+                            <br/>
                             <OutlookButtons />
                             <br/>
                             <br/>
@@ -326,6 +1410,8 @@ pub fn App() -> Html {
                     {"created by crhowell3 | v0.1.0"}
                 </footer>
             </body>
+        </ContextProvider<UseReducerHandle<NotifiedState>>>
+        </ContextProvider<UseStateHandle<RefreshState>>>
         </ContextProvider<UseStateHandle<AppState>>>
     }
 }